@@ -0,0 +1,18 @@
+use eframe::NativeOptions;
+use egui_typed_input::ValText;
+
+fn main() {
+    let mut hex_float: ValText<f64, _> = ValText::number_hex_float();
+
+    eframe::run_simple_native(
+        "hex float input",
+        NativeOptions::default(),
+        move |ctx, _frame| {
+            egui::CentralPanel::default().show(ctx, |ui| {
+                ui.label("hex float (e.g. 0x1.8p3)");
+                ui.text_edit_singleline(&mut hex_float);
+                println!("hex_float: {:?}", hex_float.get_val());
+            });
+        },
+    ).unwrap();
+}
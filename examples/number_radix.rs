@@ -0,0 +1,18 @@
+use eframe::NativeOptions;
+use egui_typed_input::ValText;
+
+fn main() {
+    let mut radix_int: ValText<i32, _> = ValText::number_int_radix();
+
+    eframe::run_simple_native(
+        "radix number input",
+        NativeOptions::default(),
+        move |ctx, _frame| {
+            egui::CentralPanel::default().show(ctx, |ui| {
+                ui.label("int (decimal, or 0x.. / 0o.. / 0b.. prefixed)");
+                ui.text_edit_singleline(&mut radix_int);
+                println!("radix_int: {:?}", radix_int.get_val());
+            });
+        },
+    ).unwrap();
+}
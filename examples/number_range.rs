@@ -0,0 +1,22 @@
+use eframe::NativeOptions;
+use egui_typed_input::ValText;
+
+fn main() {
+    let mut score: ValText<i32, _> = ValText::number_in_range(0..=10);
+
+    eframe::run_simple_native(
+        "range number input",
+        NativeOptions::default(),
+        move |ctx, _frame| {
+            egui::CentralPanel::default().show(ctx, |ui| {
+                ui.label("score (0-10)");
+                ui.text_edit_singleline(&mut score);
+                println!("score: {:?}", score.get_val());
+
+                if let Some(Ok(score)) = score.get_val() {
+                    assert!((0..=10).contains(score));
+                }
+            });
+        },
+    ).unwrap();
+}
@@ -24,21 +24,57 @@ impl ValText<Color32, egui::ecolor::ParseHexColorError> {
     }
 }
 
+/// Whether `s` is a single `_` digit-group separator being inserted after a digit
+/// (after a hex digit when the text has a `0x` prefix),
+/// as accepted by [`ValText::number`], [`ValText::number_int`], [`ValText::number_uint`],
+/// and [`ValText::number_int_radix`].
+fn is_valid_digit_separator(current_text: &str, s: &str, i: usize) -> bool {
+    let unsigned = current_text.strip_prefix(['+', '-']).unwrap_or(current_text);
+    let is_hex = unsigned.starts_with("0x") || unsigned.starts_with("0X");
+    i > 0 && s == "_" && current_text.chars().nth(i - 1)
+        .map(|c| if is_hex { c.is_ascii_hexdigit() } else { c.is_ascii_digit() })
+        .unwrap_or(false)
+}
+
 impl<T: FromStr> ValText<T, T::Err> {
-    /// Only allows (0,1,2,3,4,5,6,7,8,9,.) and (-,+) at the beginning
+    /// Only allows (0,1,2,3,4,5,6,7,8,9,.) and (-,+) at the beginning, plus scientific
+    /// notation (`1.5e-9`, `6.022E23`): a single `e`/`E` after at least one mantissa
+    /// digit, optionally followed by a single `+`/`-` and then exponent digits.\
+    /// `_` may be inserted after a digit as a visual digit-group separator (e.g. `1_000`);
+    /// it is stripped before parsing, but `as_str()` keeps it for display.
     #[must_use]
     pub fn number() -> Self {
         Self {
             text: String::new(),
             parsed_val: None,
             value_parser: Box::new(|str| {
-                str.parse()
+                str.replace('_', "").parse()
             }),
             input_validator: Box::new(|current_text, s, i| {
-                let current_has_no_dot = !current_text.contains('.');
+                if is_valid_digit_separator(current_text, s, i) {
+                    return true;
+                }
+
+                let e_pos = current_text.find(['e', 'E']);
+                let (mantissa, exponent) = match e_pos {
+                    Some(pos) => (&current_text[..pos], Some(&current_text[pos + 1..])),
+                    None => (current_text, None),
+                };
+
+                if let (Some(pos), Some(exponent)) = (e_pos.filter(|&pos| i > pos), exponent) {
+                    let sign_len = usize::from(exponent.starts_with(['+', '-']));
+                    return (exponent.is_empty() && (s.starts_with('+') || s.starts_with('-')))
+                        || (i > pos + sign_len && s.chars().all(|c| c.is_ascii_digit()));
+                }
+
+                let current_has_no_dot = !mantissa.contains('.');
+                let has_digit = current_text.chars().take(i).any(|c| c.is_ascii_digit());
+                let rest_is_digits = current_text.chars().skip(i).all(|c| c.is_ascii_digit() || c == '_');
+
                 (if i == 0 {
                     s.starts_with('+') || s.starts_with('-')
                 } else { false })
+                || (exponent.is_none() && has_digit && rest_is_digits && (s == "e" || s == "E"))
                 || s.chars().all(|c| {
                     (if current_has_no_dot { c == '.' } else { false })
                     || c.is_ascii_digit()
@@ -47,16 +83,22 @@ impl<T: FromStr> ValText<T, T::Err> {
         }
     }
 
-    /// Only allows (0,1,2,3,4,5,6,7,8,9) and (-,+) at the beginning
+    /// Only allows (0,1,2,3,4,5,6,7,8,9) and (-,+) at the beginning.\
+    /// `_` may be inserted after a digit as a visual digit-group separator (e.g. `1_000`);
+    /// it is stripped before parsing, but `as_str()` keeps it for display.
     #[must_use]
     pub fn number_int() -> Self {
         Self {
             text: String::new(),
             parsed_val: None,
             value_parser: Box::new(|str| {
-                str.parse()
+                str.replace('_', "").parse()
             }),
-            input_validator: Box::new(|_, s, i| {
+            input_validator: Box::new(|current_text, s, i| {
+                if is_valid_digit_separator(current_text, s, i) {
+                    return true;
+                }
+
                 (if i == 0 {
                     s.starts_with('+') || s.starts_with('-')
                 } else { false })
@@ -65,16 +107,22 @@ impl<T: FromStr> ValText<T, T::Err> {
         }
     }
 
-    /// Only allows (0,1,2,3,4,5,6,7,8,9) and (+) at the beginning
+    /// Only allows (0,1,2,3,4,5,6,7,8,9) and (+) at the beginning.\
+    /// `_` may be inserted after a digit as a visual digit-group separator (e.g. `1_000`);
+    /// it is stripped before parsing, but `as_str()` keeps it for display.
     #[must_use]
     pub fn number_uint() -> Self {
         Self {
             text: String::new(),
             parsed_val: None,
             value_parser: Box::new(|str| {
-                str.parse()
+                str.replace('_', "").parse()
             }),
-            input_validator: Box::new(|_, s, i| {
+            input_validator: Box::new(|current_text, s, i| {
+                if is_valid_digit_separator(current_text, s, i) {
+                    return true;
+                }
+
                 (if i == 0 {
                     s.starts_with('+')
                 } else { false })
@@ -84,174 +132,315 @@ impl<T: FromStr> ValText<T, T::Err> {
     }
 }
 
-#[derive(Debug, thiserror::Error)]
-pub enum PercentageParseError {
-    /// > 100
-    #[error("number is more then 100")]
-    OutOfRangeHigh,
-    /// < 0
-    #[error("number is less then 0")]
-    Neg,
-    #[error(transparent)]
-    ParseFloat(#[from] core::num::ParseFloatError),
-    #[error(transparent)]
-    ParseInt(#[from] core::num::ParseIntError),
+/// Integer types that can additionally be parsed from a string with an explicit radix,
+/// as used by [`ValText::number_int_radix`].
+pub trait FromStrRadix: FromStr {
+    fn from_str_radix(src: &str, radix: u32) -> Result<Self, Self::Err>;
+}
+
+macro_rules! impl_from_str_radix {
+    ($($t:ty),*) => {
+        $(impl FromStrRadix for $t {
+            fn from_str_radix(src: &str, radix: u32) -> Result<Self, Self::Err> {
+                <$t>::from_str_radix(src, radix)
+            }
+        })*
+    };
 }
 
-impl ValText<f64, PercentageParseError> {
-    // todo unit test
-    /// A numarical percentage in the range of 0-100.\
-    /// Only allows (0,1,2,3,4,5,6,7,8,9,.) and (+) at the beginning
+impl_from_str_radix!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+impl<T: FromStrRadix> ValText<T, T::Err> {
+    /// Like [`ValText::number_int`], but also accepts integers written with a `0x`, `0o`,
+    /// or `0b` prefix, which are parsed in hex, octal, or binary respectively.\
+    /// Only allows (0,1,2,3,4,5,6,7,8,9,a-f,A-F) as appropriate for the active radix,
+    /// (x,o,b,X,O,B) as a prefix after a leading `0`, and (-,+) at the beginning.\
+    /// `_` may be inserted after a digit as a visual digit-group separator (e.g. `0xff_ff`);
+    /// it is stripped before parsing, but `as_str()` keeps it for display.
     #[must_use]
-    pub fn percentage() -> Self {
+    pub fn number_int_radix() -> Self {
         Self {
             text: String::new(),
             parsed_val: None,
             value_parser: Box::new(|str| {
-                let num = str.parse();
-                match num {
-                    Ok(num) => {
-                        if num > 100.0 {
-                            Err(PercentageParseError::OutOfRangeHigh)
-                        } else if num < 0.0 {
-                            Err(PercentageParseError::Neg)
-                        } else {
-                            Ok(num)
-                        }
-                    },
-                    Err(e) => Err(e.into()),
+                let str = &str.replace('_', "");
+                let (sign, unsigned) = match str.as_bytes().first() {
+                    Some(b'-') => ("-", &str[1..]),
+                    Some(b'+') => ("", &str[1..]),
+                    _ => ("", str),
+                };
+                if let Some(body) = unsigned.strip_prefix("0x").or_else(|| unsigned.strip_prefix("0X")) {
+                    T::from_str_radix(&format!("{sign}{body}"), 16)
+                } else if let Some(body) = unsigned.strip_prefix("0o").or_else(|| unsigned.strip_prefix("0O")) {
+                    T::from_str_radix(&format!("{sign}{body}"), 8)
+                } else if let Some(body) = unsigned.strip_prefix("0b").or_else(|| unsigned.strip_prefix("0B")) {
+                    T::from_str_radix(&format!("{sign}{body}"), 2)
+                } else {
+                    str.parse()
                 }
             }),
             input_validator: Box::new(|current_text, s, i| {
-                let current_text_no_des_len = current_text.split_once('.')
-                    .map(|(pre_dot, _)| pre_dot.len())
-                    .unwrap_or(current_text.len());
-                if current_text_no_des_len + s.len() > 3 && !current_text.contains('.') { return false; }
-
-                let current_has_no_dot = !current_text.contains('.');
-                let all_num_or_dot = s.chars().all(|c| {
-                    (if current_has_no_dot { c == '.' } else { false })
-                    || c.is_ascii_digit()
-                });
-
-                if !current_text.is_empty() && current_text.as_bytes()[i.saturating_sub(1)] == b'.' && all_num_or_dot {
+                if is_valid_digit_separator(current_text, s, i) {
                     return true;
                 }
 
-                // only allow therd char if others are 00
-                if current_text_no_des_len == 2 {
-                    if s.starts_with('.') && all_num_or_dot {
-                        return true;
-                    } else if s == "0" {
-                        return current_text.starts_with("10") && !current_text.contains('.');
+                if i == 0 {
+                    return s.starts_with('+') || s.starts_with('-') || s.chars().all(|c| c.is_ascii_digit());
+                }
+
+                // skip a leading sign so the radix prefix is detected regardless of it
+                let sign_len = usize::from(current_text.starts_with('+') || current_text.starts_with('-'));
+                let unsigned_text = &current_text[sign_len..];
+                let unsigned_i = i - sign_len;
+
+                if unsigned_i == 1 && unsigned_text == "0" {
+                    if let Some(c) = s.chars().next() {
+                        if s.chars().count() == 1 && matches!(c, 'x' | 'o' | 'b' | 'X' | 'O' | 'B') {
+                            return true;
+                        }
                     }
-                    return false;
                 }
 
-                (if i == 0 {
-                    s.starts_with('+')
-                } else { false })
-                || all_num_or_dot
+                let radix = if unsigned_text.starts_with("0x") || unsigned_text.starts_with("0X") {
+                    Some(16)
+                } else if unsigned_text.starts_with("0o") || unsigned_text.starts_with("0O") {
+                    Some(8)
+                } else if unsigned_text.starts_with("0b") || unsigned_text.starts_with("0B") {
+                    Some(2)
+                } else {
+                    None
+                };
+
+                s.chars().all(|c| match radix {
+                    Some(16) => c.is_ascii_hexdigit(),
+                    Some(8) => ('0'..='7').contains(&c),
+                    Some(2) => c == '0' || c == '1',
+                    _ => c.is_ascii_digit(),
+                })
             })
         }
     }
 }
 
-impl ValText<f32, PercentageParseError> {
-    /// A numarical percentage in the range of 0-100.\
-    /// Only allows (0,1,2,3,4,5,6,7,8,9,.) and (+) at the beginning
+#[derive(Debug, thiserror::Error)]
+pub enum RangeParseError<E> {
+    /// Above the allowed maximum of the range
+    #[error("number is above the allowed maximum")]
+    OutOfRangeHigh,
+    /// Below the allowed minimum of the range
+    #[error("number is below the allowed minimum")]
+    OutOfRangeLow,
+    #[error(transparent)]
+    Parse(#[from] E),
+}
+
+/// Numeric types that report which extra syntax their `FromStr` impl actually accepts,
+/// as used by [`ValText::number_in_range`] to avoid offering syntax `T` cannot parse
+/// (`.` on an integer field, `-` on an unsigned one). A leading `+` is accepted by every `FromStr` numeric impl (signed
+/// or not), so only `-` needs to be gated on signedness.
+pub trait NumericSyntax {
+    const ALLOWS_MINUS: bool;
+    const ALLOWS_DOT: bool;
+}
+
+macro_rules! impl_numeric_syntax {
+    ($(($t:ty, $allows_minus:expr, $allows_dot:expr)),* $(,)?) => {
+        $(impl NumericSyntax for $t {
+            const ALLOWS_MINUS: bool = $allows_minus;
+            const ALLOWS_DOT: bool = $allows_dot;
+        })*
+    };
+}
+
+impl_numeric_syntax!(
+    (i8, true, false), (i16, true, false), (i32, true, false),
+    (i64, true, false), (i128, true, false), (isize, true, false),
+    (u8, false, false), (u16, false, false), (u32, false, false),
+    (u64, false, false), (u128, false, false), (usize, false, false),
+    (f32, true, true), (f64, true, true),
+);
+
+impl<T: FromStr + PartialOrd + Clone + NumericSyntax + 'static> ValText<T, RangeParseError<T::Err>> {
+    /// A numerical value restricted to `range`, as in `toml_edit`'s ranged values.\
+    /// Accepts the same characters as [`ValText::number`] except the `e`/`E` of scientific
+    /// notation (and only those `T` actually supports, e.g. no `-`/`.` for an unsigned
+    /// integer); keystrokes are rejected
+    /// optimistically, only once the integer part already typed unambiguously
+    /// exceeds `*range.end()`.
     #[must_use]
-    pub fn percentage() -> Self {
+    pub fn number_in_range(range: core::ops::RangeInclusive<T>) -> Self {
+        let lo = range.start().clone();
+        let hi = range.end().clone();
+        let hi_for_validator = hi.clone();
+
         Self {
             text: String::new(),
             parsed_val: None,
-            value_parser: Box::new(|str| {
-                let num = str.parse();
-                match num {
-                    Ok(num) => {
-                        if num > 100.0 {
-                            Err(PercentageParseError::OutOfRangeHigh)
-                        } else if num < 0.0 {
-                            Err(PercentageParseError::Neg)
-                        } else {
-                            Ok(num)
-                        }
-                    },
-                    Err(e) => Err(e.into()),
+            value_parser: Box::new(move |str| {
+                let num: T = str.replace('_', "").parse()?;
+                if num > hi {
+                    Err(RangeParseError::OutOfRangeHigh)
+                } else if num < lo {
+                    Err(RangeParseError::OutOfRangeLow)
+                } else {
+                    Ok(num)
                 }
             }),
-            input_validator: Box::new(|current_text, s, i| {
-                let current_text_no_des_len = current_text.split_once('.')
-                    .map(|(pre_dot, _)| pre_dot.len())
-                    .unwrap_or(current_text.len());
-                if current_text_no_des_len + s.len() > 3 && !current_text.contains('.') { return false; }
-                
+            input_validator: Box::new(move |current_text, s, i| {
+                if is_valid_digit_separator(current_text, s, i) {
+                    return true;
+                }
+
                 let current_has_no_dot = !current_text.contains('.');
                 let all_num_or_dot = s.chars().all(|c| {
-                    (if current_has_no_dot { c == '.' } else { false })
+                    (if T::ALLOWS_DOT && current_has_no_dot { c == '.' } else { false })
                     || c.is_ascii_digit()
                 });
+                let is_leading_sign = i == 0 && (s.starts_with('+') || (T::ALLOWS_MINUS && s.starts_with('-')));
 
-                if !current_text.is_empty() && current_text.as_bytes()[i.saturating_sub(1)] == b'.' && all_num_or_dot {
-                    return true;
-                }
-
-                // only allow therd char if others are 00
-                if current_text_no_des_len == 2 {
-                    if s.starts_with('.') && all_num_or_dot {
-                        return true;
-                    } else if s == "0" {
-                        return current_text.starts_with("10") && !current_text.contains('.');
-                    }
+                if !all_num_or_dot && !is_leading_sign {
                     return false;
                 }
 
-                (if i == 0 {
-                    s.starts_with('+')
-                } else { false })
-                || all_num_or_dot
+                let mut candidate: String = current_text.chars().take(i).collect();
+                candidate.push_str(s);
+                candidate.extend(current_text.chars().skip(i));
+                let candidate = candidate.replace('_', "");
+
+                match candidate.split('.').next().unwrap_or(&candidate).parse::<T>() {
+                    // more digits after a `-` only lower the value, so it is never unambiguously too high
+                    Ok(int_part) => candidate.starts_with('-') || int_part <= hi_for_validator,
+                    Err(_) => true,
+                }
             })
         }
     }
 }
 
-impl ValText<u32, PercentageParseError> {
-    /// A numarical percentage in the range of 0-100.\
-    /// Only allows (0,1,2,3,4,5,6,7,8,9) and (+) at the beginning
+impl ValText<f64, RangeParseError<core::num::ParseFloatError>> {
+    /// A numarical percentage in the range of 0-100.
+    #[must_use]
+    pub fn percentage() -> Self {
+        Self::number_in_range(0.0..=100.0)
+    }
+}
+
+impl ValText<f32, RangeParseError<core::num::ParseFloatError>> {
+    /// A numarical percentage in the range of 0-100.
+    #[must_use]
+    pub fn percentage() -> Self {
+        Self::number_in_range(0.0..=100.0)
+    }
+}
+
+impl ValText<u32, RangeParseError<core::num::ParseIntError>> {
+    /// A numarical percentage in the range of 0-100.
     #[must_use]
     pub fn percentage_uint() -> Self {
+        Self::number_in_range(0..=100)
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum ParseHexFloatError {
+    #[error("hex floats must start with 0x")]
+    MissingPrefix,
+    #[error("hex floats must have a p/P binary exponent")]
+    MissingExponent,
+    #[error("invalid hex digit in mantissa")]
+    InvalidMantissa,
+    #[error(transparent)]
+    InvalidExponent(#[from] core::num::ParseIntError),
+}
+
+/// Parses a C99/WGSL-style hex float such as `0x1.8p3`: a `0x` prefix, a hex integer
+/// and/or fractional part, and a required `p`/`P` binary exponent.
+fn parse_hex_float(str: &str) -> Result<f64, ParseHexFloatError> {
+    let body = str.strip_prefix("0x").or_else(|| str.strip_prefix("0X"))
+        .ok_or(ParseHexFloatError::MissingPrefix)?;
+
+    let p_pos = body.find(['p', 'P']).ok_or(ParseHexFloatError::MissingExponent)?;
+    let (mantissa, exponent) = (&body[..p_pos], &body[p_pos + 1..]);
+
+    let (int_part, frac_part) = match mantissa.split_once('.') {
+        Some((int_part, frac_part)) => (int_part, frac_part),
+        None => (mantissa, ""),
+    };
+    if int_part.is_empty() && frac_part.is_empty() {
+        return Err(ParseHexFloatError::InvalidMantissa);
+    }
+    if !int_part.chars().all(|c| c.is_ascii_hexdigit()) || !frac_part.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Err(ParseHexFloatError::InvalidMantissa);
+    }
+
+    let int_val = if int_part.is_empty() {
+        0
+    } else {
+        u64::from_str_radix(int_part, 16).map_err(|_| ParseHexFloatError::InvalidMantissa)?
+    };
+    let frac_val = frac_part.chars().enumerate().fold(0f64, |acc, (idx, c)| {
+        acc + f64::from(c.to_digit(16).unwrap()) / 16f64.powi(idx as i32 + 1)
+    });
+
+    let exponent: i32 = exponent.parse()?;
+
+    Ok((int_val as f64 + frac_val) * 2f64.powi(exponent))
+}
+
+/// Whether `s` is a valid insertion into a hex float at `current_text`/`i`, as accepted
+/// by [`ValText::<f64, _>::number_hex_float`] and [`ValText::<f32, _>::number_hex_float`].
+fn hex_float_input_validator(current_text: &str, s: &str, i: usize) -> bool {
+    if i == 0 {
+        return s == "0";
+    }
+    if i == 1 {
+        return current_text == "0" && (s == "x" || s == "X");
+    }
+
+    let body = &current_text[2..];
+    let p_pos = body.find(['p', 'P']);
+    match p_pos {
+        Some(p_pos) if i > p_pos + 2 => {
+            let exponent = &body[p_pos + 1..];
+            let sign_len = usize::from(exponent.starts_with(['+', '-']));
+            (exponent.is_empty() && (s.starts_with('+') || s.starts_with('-')))
+                || (i > p_pos + 2 + sign_len && s.chars().all(|c| c.is_ascii_digit()))
+        }
+        _ => {
+            let has_dot = body[..p_pos.unwrap_or(body.len())].contains('.');
+            let rest_is_digits = current_text.chars().skip(i).all(|c| c.is_ascii_digit());
+            (p_pos.is_none() && rest_is_digits && (s == "p" || s == "P"))
+                || s.chars().all(|c| {
+                    (if !has_dot { c == '.' } else { false })
+                    || c.is_ascii_hexdigit()
+                })
+        }
+    }
+}
+
+impl ValText<f64, ParseHexFloatError> {
+    /// A hex float such as `0x1.8p3` (C99/WGSL style): a `0x`-prefixed hex mantissa,
+    /// optionally with a fractional part, followed by a required `p`/`P` binary exponent.
+    #[must_use]
+    pub fn number_hex_float() -> Self {
         Self {
             text: String::new(),
-            parsed_val: None,
-            value_parser: Box::new(|str| {
-                let num = str.parse();
-                match num {
-                    Ok(num) => {
-                        if num > 100 {
-                            Err(PercentageParseError::OutOfRangeHigh)
-                        } else {
-                            Ok(num)
-                        }
-                    },
-                    Err(e) => Err(e.into()),
-                }
-            }),
-            input_validator: Box::new(|current_text, s, i| {
-                if current_text.len() + s.len() > 3 { return false; }
-
-                // only allow therd char if others are 00
-                if current_text.len() == 2 {
-                    if s == "0" {
-                        return current_text.starts_with("10");
-                    }
-                    return false;
-                }
+            parsed_val: Some(Err(ParseHexFloatError::MissingPrefix)),
+            value_parser: Box::new(parse_hex_float),
+            input_validator: Box::new(hex_float_input_validator),
+        }
+    }
+}
 
-                (if i == 0 {
-                    s.starts_with('+')
-                } else { false })
-                || s.chars().all(|c| c.is_ascii_digit())
-            })
+impl ValText<f32, ParseHexFloatError> {
+    /// See [`ValText::<f64, _>::number_hex_float`].
+    #[must_use]
+    pub fn number_hex_float() -> Self {
+        Self {
+            text: String::new(),
+            parsed_val: Some(Err(ParseHexFloatError::MissingPrefix)),
+            value_parser: Box::new(|str| parse_hex_float(str).map(|v| v as f32)),
+            input_validator: Box::new(hex_float_input_validator),
         }
     }
 }